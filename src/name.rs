@@ -46,6 +46,110 @@ pub mod name_id {
 }
 
 
+/// A typed [name ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-ids).
+///
+/// Unlike the raw constants in the [`name_id`](name_id/index.html) module, this enum
+/// lets callers `match` on known IDs while still accepting vendor-specific ones
+/// via [`Other`](NameId::Other).
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[allow(missing_docs)]
+pub enum NameId {
+    Copyright,
+    Family,
+    SubFamily,
+    UniqueId,
+    Full,
+    Version,
+    PostScript,
+    Trademark,
+    Manufacturer,
+    Designer,
+    Description,
+    VendorUrl,
+    DesignerUrl,
+    License,
+    LicenseUrl,
+    TypographicFamily,
+    TypographicSubFamily,
+    CompatibleFull,
+    SampleText,
+    PostScriptCid,
+    WwsFamily,
+    WwsSubFamily,
+    LightBackgroundPalette,
+    DarkBackgroundPalette,
+    VariationsPostScriptNamePrefix,
+    /// An unknown or vendor-specific name ID.
+    Other(u16),
+}
+
+impl NameId {
+    /// Parses a `NameId` from a raw name ID value.
+    pub fn from_u16(n: u16) -> Self {
+        match n {
+            name_id::COPYRIGHT_NOTICE => NameId::Copyright,
+            name_id::FAMILY => NameId::Family,
+            name_id::SUBFAMILY => NameId::SubFamily,
+            name_id::UNIQUE_ID => NameId::UniqueId,
+            name_id::FULL_NAME => NameId::Full,
+            name_id::VERSION => NameId::Version,
+            name_id::POST_SCRIPT_NAME => NameId::PostScript,
+            name_id::TRADEMARK => NameId::Trademark,
+            name_id::MANUFACTURER => NameId::Manufacturer,
+            name_id::DESIGNER => NameId::Designer,
+            name_id::DESCRIPTION => NameId::Description,
+            name_id::VENDOR_URL => NameId::VendorUrl,
+            name_id::DESIGNER_URL => NameId::DesignerUrl,
+            name_id::LICENSE => NameId::License,
+            name_id::LICENSE_URL => NameId::LicenseUrl,
+            name_id::TYPOGRAPHIC_FAMILY => NameId::TypographicFamily,
+            name_id::TYPOGRAPHIC_SUBFAMILY => NameId::TypographicSubFamily,
+            name_id::COMPATIBLE_FULL => NameId::CompatibleFull,
+            name_id::SAMPLE_TEXT => NameId::SampleText,
+            name_id::POST_SCRIPT_CID => NameId::PostScriptCid,
+            name_id::WWS_FAMILY => NameId::WwsFamily,
+            name_id::WWS_SUBFAMILY => NameId::WwsSubFamily,
+            name_id::LIGHT_BACKGROUND_PALETTE => NameId::LightBackgroundPalette,
+            name_id::DARK_BACKGROUND_PALETTE => NameId::DarkBackgroundPalette,
+            name_id::VARIATIONS_POST_SCRIPT_NAME_PREFIX => NameId::VariationsPostScriptNamePrefix,
+            n => NameId::Other(n),
+        }
+    }
+
+    /// Converts a `NameId` back into its raw name ID value.
+    pub fn to_u16(&self) -> u16 {
+        match *self {
+            NameId::Copyright => name_id::COPYRIGHT_NOTICE,
+            NameId::Family => name_id::FAMILY,
+            NameId::SubFamily => name_id::SUBFAMILY,
+            NameId::UniqueId => name_id::UNIQUE_ID,
+            NameId::Full => name_id::FULL_NAME,
+            NameId::Version => name_id::VERSION,
+            NameId::PostScript => name_id::POST_SCRIPT_NAME,
+            NameId::Trademark => name_id::TRADEMARK,
+            NameId::Manufacturer => name_id::MANUFACTURER,
+            NameId::Designer => name_id::DESIGNER,
+            NameId::Description => name_id::DESCRIPTION,
+            NameId::VendorUrl => name_id::VENDOR_URL,
+            NameId::DesignerUrl => name_id::DESIGNER_URL,
+            NameId::License => name_id::LICENSE,
+            NameId::LicenseUrl => name_id::LICENSE_URL,
+            NameId::TypographicFamily => name_id::TYPOGRAPHIC_FAMILY,
+            NameId::TypographicSubFamily => name_id::TYPOGRAPHIC_SUBFAMILY,
+            NameId::CompatibleFull => name_id::COMPATIBLE_FULL,
+            NameId::SampleText => name_id::SAMPLE_TEXT,
+            NameId::PostScriptCid => name_id::POST_SCRIPT_CID,
+            NameId::WwsFamily => name_id::WWS_FAMILY,
+            NameId::WwsSubFamily => name_id::WWS_SUBFAMILY,
+            NameId::LightBackgroundPalette => name_id::LIGHT_BACKGROUND_PALETTE,
+            NameId::DarkBackgroundPalette => name_id::DARK_BACKGROUND_PALETTE,
+            NameId::VariationsPostScriptNamePrefix => name_id::VARIATIONS_POST_SCRIPT_NAME_PREFIX,
+            NameId::Other(n) => n,
+        }
+    }
+}
+
+
 /// A [platform ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#platform-ids).
 #[derive(Clone, Copy, PartialEq, Debug)]
 #[allow(missing_docs)]
@@ -84,12 +188,113 @@ fn is_unicode_encoding(platform_id: PlatformId, encoding_id: u16) -> bool {
     }
 }
 
+#[cfg(feature = "std")]
+#[inline]
+fn is_mac_roman_encoding(platform_id: PlatformId, encoding_id: u16) -> bool {
+    const MACINTOSH_ROMAN_ENCODING_ID: u16 = 0;
+
+    platform_id == PlatformId::Macintosh && encoding_id == MACINTOSH_ROMAN_ENCODING_ID
+}
+
+// https://en.wikipedia.org/wiki/Mac_OS_Roman
+//
+// Maps bytes 0x80..=0xFF to their Unicode scalar value. Bytes 0x00..=0x7F
+// are identical to ASCII/Unicode and are not listed here.
+#[cfg(feature = "std")]
+const MAC_ROMAN_TABLE: [u16; 128] = [
+    0x00C4, 0x00C5, 0x00C7, 0x00C9, 0x00D1, 0x00D6, 0x00DC, 0x00E1,
+    0x00E0, 0x00E2, 0x00E4, 0x00E3, 0x00E5, 0x00E7, 0x00E9, 0x00E8,
+    0x00EA, 0x00EB, 0x00ED, 0x00EC, 0x00EE, 0x00EF, 0x00F1, 0x00F3,
+    0x00F2, 0x00F4, 0x00F6, 0x00F5, 0x00FA, 0x00F9, 0x00FB, 0x00FC,
+    0x2020, 0x00B0, 0x00A2, 0x00A3, 0x00A7, 0x2022, 0x00B6, 0x00DF,
+    0x00AE, 0x00A9, 0x2122, 0x00B4, 0x00A8, 0x2260, 0x00C6, 0x00D8,
+    0x221E, 0x00B1, 0x2264, 0x2265, 0x00A5, 0x00B5, 0x2202, 0x2211,
+    0x220F, 0x03C0, 0x222B, 0x00AA, 0x00BA, 0x03A9, 0x00E6, 0x00F8,
+    0x00BF, 0x00A1, 0x00AC, 0x221A, 0x0192, 0x2248, 0x2206, 0x00AB,
+    0x00BB, 0x2026, 0x00A0, 0x00C0, 0x00C3, 0x00D5, 0x0152, 0x0153,
+    0x2013, 0x2014, 0x201C, 0x201D, 0x2018, 0x2019, 0x00F7, 0x25CA,
+    0x00FF, 0x0178, 0x2044, 0x20AC, 0x2039, 0x203A, 0xFB01, 0xFB02,
+    0x2021, 0x00B7, 0x201A, 0x201E, 0x2030, 0x00C2, 0x00CA, 0x00C1,
+    0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF, 0x00CC, 0x00D3, 0x00D4,
+    0xF8FF, 0x00D2, 0x00DA, 0x00DB, 0x00D9, 0x0131, 0x02C6, 0x02DC,
+    0x00AF, 0x02D8, 0x02D9, 0x02DA, 0x00B8, 0x02DD, 0x02DB, 0x02C7,
+];
+
+
+/// A BCP-47-ish language tag associated with a [`Name`](struct.Name.html).
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub enum LanguageTag {
+    /// A tag embedded in the font's `name` table as a format 1 language-tag record.
+    Custom(String),
+    /// A tag resolved from a well-known Windows LCID or Macintosh language code.
+    Predefined(&'static str),
+}
+
+#[cfg(feature = "std")]
+impl LanguageTag {
+    /// Returns the tag as a string slice.
+    pub fn as_str(&self) -> &str {
+        match self {
+            LanguageTag::Custom(s) => s,
+            LanguageTag::Predefined(s) => s,
+        }
+    }
+}
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#windows-language-ids
+#[cfg(feature = "std")]
+fn windows_language_tag(lang_id: u16) -> Option<&'static str> {
+    Some(match lang_id {
+        0x0409 => "en",
+        0x0407 => "de",
+        0x040C => "fr",
+        0x0410 => "it",
+        0x040A => "es",
+        0x0413 => "nl",
+        0x041D => "sv",
+        0x0406 => "da",
+        0x0414 => "nb",
+        0x040B => "fi",
+        0x0416 => "pt",
+        0x0419 => "ru",
+        0x0415 => "pl",
+        0x0411 => "ja",
+        0x0412 => "ko",
+        0x0804 => "zh",
+        _ => return None,
+    })
+}
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#macintosh-language-ids
+#[cfg(feature = "std")]
+fn mac_language_tag(lang_id: u16) -> Option<&'static str> {
+    Some(match lang_id {
+        0 => "en",
+        1 => "fr",
+        2 => "de",
+        3 => "it",
+        4 => "nl",
+        5 => "sv",
+        6 => "es",
+        8 => "pt",
+        9 => "nb",
+        11 => "ja",
+        12 => "ar",
+        14 => "el",
+        23 => "ko",
+        32 => "ru",
+        _ => return None,
+    })
+}
+
 
 /// A [Name Record](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-records).
 #[derive(Clone, Copy)]
 pub struct Name<'a> {
     data: raw::NameRecord,
     strings: &'a [u8],
+    lang_tags: &'a [u8],
 }
 
 impl<'a> Name<'a> {
@@ -108,6 +313,46 @@ impl<'a> Name<'a> {
         self.data.language_id()
     }
 
+    /// Resolves the [`language_id`](#method.language_id) to a language tag.
+    ///
+    /// For `language_id >= 0x8000` the tag is looked up in the naming table's
+    /// format 1 `langTagRecords` (only present if the font embeds one). Otherwise
+    /// the ID is resolved as a Windows LCID or a Macintosh language code,
+    /// depending on [`platform_id`](#method.platform_id).
+    #[cfg(feature = "std")]
+    pub fn language(&self) -> Option<LanguageTag> {
+        let lang_id = self.language_id();
+        if lang_id >= 0x8000 {
+            self.lang_tag_at((lang_id - 0x8000) as usize).map(LanguageTag::Custom)
+        } else if self.platform_id() == Some(PlatformId::Macintosh) {
+            mac_language_tag(lang_id).map(LanguageTag::Predefined)
+        } else {
+            windows_language_tag(lang_id).map(LanguageTag::Predefined)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn lang_tag_at(&self, index: usize) -> Option<String> {
+        const LANG_TAG_RECORD_SIZE: usize = 4;
+
+        let start = index * LANG_TAG_RECORD_SIZE;
+        let data = self.lang_tags.get(start..start + LANG_TAG_RECORD_SIZE)?;
+        let mut s = Stream::new(data);
+        let length: u16 = s.read().ok()?;
+        let offset: u16 = s.read().ok()?;
+
+        let start = offset as usize;
+        let end = start + length as usize;
+        let bytes = self.strings.get(start..end)?;
+
+        let mut tag: Vec<u16> = Vec::new();
+        for c in LazyArray::new(bytes) {
+            tag.push(c);
+        }
+
+        String::from_utf16(&tag).ok()
+    }
+
     /// Parses the [Name ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-ids).
     ///
     /// A predefined list of ID's can be found in the [`name_id`](name_id/index.html) module.
@@ -115,6 +360,12 @@ impl<'a> Name<'a> {
         self.data.name_id()
     }
 
+    /// Parses the [Name ID](https://docs.microsoft.com/en-us/typography/opentype/spec/name#name-ids)
+    /// as a typed [`NameId`].
+    pub fn name_id_typed(&self) -> NameId {
+        NameId::from_u16(self.name_id())
+    }
+
     /// Parses the Name's data as bytes.
     ///
     /// Can be empty.
@@ -126,17 +377,20 @@ impl<'a> Name<'a> {
 
     /// Parses the Name's data as a UTF-8 string.
     ///
-    /// Only Unicode names are supported. And since they are stored as UTF-16BE,
-    /// we can't return `&str` and have to allocate a `String`.
+    /// Since the underlying data is stored either as UTF-16BE or as a single-byte
+    /// Mac Roman encoding, we can't return `&str` and have to allocate a `String`.
     ///
     /// Supports:
     /// - Unicode Platform ID
     /// - Windows Platform ID + Unicode BMP
+    /// - Macintosh Platform ID + Mac Roman encoding
     #[cfg(feature = "std")]
     #[inline(never)]
     pub fn name_utf8(&self) -> Option<String> {
         if self.is_unicode() {
             self.name_from_utf16_be()
+        } else if self.is_mac_roman() {
+            self.name_from_mac_roman()
         } else {
             None
         }
@@ -148,6 +402,15 @@ impl<'a> Name<'a> {
         is_unicode_encoding(self.platform_id().unwrap(), self.encoding_id())
     }
 
+    #[cfg(feature = "std")]
+    #[inline]
+    fn is_mac_roman(&self) -> bool {
+        match self.platform_id() {
+            Some(id) => is_mac_roman_encoding(id, self.encoding_id()),
+            None => false,
+        }
+    }
+
     #[cfg(feature = "std")]
     #[inline(never)]
     fn name_from_utf16_be(&self) -> Option<String> {
@@ -158,6 +421,23 @@ impl<'a> Name<'a> {
 
         String::from_utf16(&name).ok()
     }
+
+    #[cfg(feature = "std")]
+    #[inline(never)]
+    fn name_from_mac_roman(&self) -> Option<String> {
+        // Every byte has a mapping, so this never fails.
+        let mut name = String::with_capacity(self.name().len());
+        for &b in self.name() {
+            if b < 0x80 {
+                name.push(b as char);
+            } else {
+                let c = MAC_ROMAN_TABLE[(b - 0x80) as usize];
+                name.push(core::char::from_u32(c as u32)?);
+            }
+        }
+
+        Some(name)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -197,15 +477,17 @@ impl<'a> core::fmt::Debug for Name<'a> {
 pub struct Names<'a> {
     names: &'a [u8],
     storage: &'a [u8],
+    lang_tags: &'a [u8],
     index: u16,
     total: u16,
 }
 
 impl<'a> Names<'a> {
-    fn new(names: &'a [u8], storage: &'a [u8]) -> Self {
+    fn new(names: &'a [u8], storage: &'a [u8], lang_tags: &'a [u8]) -> Self {
         Names {
             names,
             storage,
+            lang_tags,
             index: 0,
             total: (names.len() / raw::NameRecord::SIZE) as u16,
         }
@@ -232,6 +514,7 @@ impl<'a> Iterator for Names<'a> {
         Some(Name {
             data: raw::NameRecord::new(data),
             strings: self.storage,
+            lang_tags: self.lang_tags,
         })
     }
 }
@@ -244,7 +527,7 @@ impl<'a> Font<'a> {
     pub fn names(&self) -> Names {
         match self._names() {
             Ok(v) => v,
-            Err(_) => Names { names: &[], storage: &[], index: 0, total: 0 },
+            Err(_) => Names { names: &[], storage: &[], lang_tags: &[], index: 0, total: 0 },
         }
     }
 
@@ -260,15 +543,17 @@ impl<'a> Font<'a> {
         s.skip::<u16>(); // offset
 
         if format == 0 {
-            Ok(Names::new(s.read_bytes(raw::NameRecord::SIZE as u32 * count as u32)?, s.tail()?))
+            let names = s.read_bytes(raw::NameRecord::SIZE as u32 * count as u32)?;
+            Ok(Names::new(names, s.tail()?, &[]))
         } else if format == 1 {
             let lang_tag_count: u16 = s.read()?;
             let lang_tag_len = lang_tag_count
                 .checked_mul(LANG_TAG_RECORD_SIZE)
                 .ok_or_else(|| Error::NotATrueType)?;
 
-            s.advance(lang_tag_len); // langTagRecords
-            Ok(Names::new(s.read_bytes(raw::NameRecord::SIZE as u32 * count as u32)?, s.tail()?))
+            let lang_tags = s.read_bytes(lang_tag_len as u32)?; // langTagRecords
+            let names = s.read_bytes(raw::NameRecord::SIZE as u32 * count as u32)?;
+            Ok(Names::new(names, s.tail()?, lang_tags))
         } else {
             // Invalid format.
             // The error type doesn't matter, since we will ignore it anyway.
@@ -288,18 +573,19 @@ impl<'a> Font<'a> {
         let mut idx = None;
         let mut iter = self.names();
         for (i, name) in iter.enumerate() {
-            if name.name_id() == name_id::TYPOGRAPHIC_FAMILY && name.is_unicode() {
+            let is_decodable = name.is_unicode() || name.is_mac_roman();
+            if name.name_id() == name_id::TYPOGRAPHIC_FAMILY && is_decodable {
                 // Break the loop as soon as we reached 'Typographic Family'.
                 idx = Some(i);
                 break;
-            } else if name.name_id() == name_id::FAMILY && name.is_unicode() {
+            } else if name.name_id() == name_id::FAMILY && is_decodable {
                 idx = Some(i);
                 // Do not break the loop since 'Typographic Family' can be set later
                 // and it has a higher priority.
             }
         }
 
-        iter.nth(idx?).and_then(|name| name.name_from_utf16_be())
+        iter.nth(idx?).and_then(|name| name.name_utf8())
     }
 
     /// Returns font's PostScript name.
@@ -310,7 +596,113 @@ impl<'a> Font<'a> {
     #[cfg(feature = "std")]
     pub fn post_script_name(&self) -> Option<String> {
         self.names()
-            .find(|name| name.name_id() == name_id::POST_SCRIPT_NAME && name.is_unicode())
-            .and_then(|name| name.name_from_utf16_be())
+            .find(|name| {
+                name.name_id() == name_id::POST_SCRIPT_NAME
+                    && (name.is_unicode() || name.is_mac_roman())
+            })
+            .and_then(|name| name.name_utf8())
+    }
+
+    /// Returns a name for the given [`NameId`], preferring one of the given languages.
+    ///
+    /// `preferred` is a list of BCP-47-ish language tags (e.g. `"en"`, `"de"`) in
+    /// priority order, as returned by [`Name::language`](struct.Name.html#method.language).
+    /// The first decodable name whose language matches an entry in `preferred` wins;
+    /// failing that, the first English name is used; failing that, any decodable name
+    /// with a matching [`NameId`] is returned.
+    #[cfg(feature = "std")]
+    pub fn name_for_id(&self, id: NameId, preferred: &[&str]) -> Option<String> {
+        for &lang in preferred {
+            let found = self.names()
+                .filter(|name| {
+                    name.name_id_typed() == id
+                        && (name.is_unicode() || name.is_mac_roman())
+                        && name.language().map_or(false, |tag| tag.as_str().eq_ignore_ascii_case(lang))
+                })
+                .find_map(|name| name.name_utf8());
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        let english = self.names()
+            .filter(|name| {
+                name.name_id_typed() == id
+                    && (name.is_unicode() || name.is_mac_roman())
+                    && name.language().map_or(false, |tag| tag.as_str().eq_ignore_ascii_case("en"))
+            })
+            .find_map(|name| name.name_utf8());
+
+        if english.is_some() {
+            return english;
+        }
+
+        self.names()
+            .filter(|name| name.name_id_typed() == id)
+            .find_map(|name| name.name_utf8())
+    }
+
+    /// Returns font's PostScript name, validated and sanitized per the OpenType spec.
+    ///
+    /// PostScript names are restricted to printable ASCII `0x21..=0x7E`, excluding
+    /// `[`, `]`, `(`, `)`, `{`, `}`, `<`, `>`, `/` and `%`, with a 63-character maximum.
+    /// Disallowed characters are stripped rather than rejecting the whole name;
+    /// `None` is returned if nothing valid remains.
+    ///
+    /// Note that font can have multiple names. You can use [`names()`] to list them all.
+    ///
+    /// [`names()`]: #method.names
+    #[cfg(feature = "std")]
+    pub fn post_script_name_validated(&self) -> Option<String> {
+        const MAX_LEN: usize = 63;
+
+        let name = self.names().find(|name| {
+            name.name_id() == name_id::POST_SCRIPT_NAME
+                && (name.is_unicode() || name.is_mac_roman())
+        })?;
+
+        let mut out = String::new();
+        if name.is_unicode() {
+            for chunk in name.name().chunks_exact(2) {
+                if chunk[0] != 0 {
+                    continue;
+                }
+
+                if is_valid_post_script_char(chunk[1]) {
+                    out.push(chunk[1] as char);
+                }
+
+                if out.len() == MAX_LEN {
+                    break;
+                }
+            }
+        } else {
+            for &b in name.name() {
+                if is_valid_post_script_char(b) {
+                    out.push(b as char);
+                }
+
+                if out.len() == MAX_LEN {
+                    break;
+                }
+            }
+        }
+
+        if out.is_empty() {
+            None
+        } else {
+            Some(out)
+        }
+    }
+}
+
+// https://docs.microsoft.com/en-us/typography/opentype/spec/name#nameid-6
+#[cfg(feature = "std")]
+#[inline]
+fn is_valid_post_script_char(b: u8) -> bool {
+    match b {
+        0x21..=0x7E => !matches!(b, b'[' | b']' | b'(' | b')' | b'{' | b'}' | b'<' | b'>' | b'/' | b'%'),
+        _ => false,
     }
 }